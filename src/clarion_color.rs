@@ -78,6 +78,24 @@ impl From<RgbColor> for ClarionColor {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for ClarionColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.color)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ClarionColor {
+    /// Deserializes a `ClarionColor`, re-running the `ClarionColor::MIN..=MAX`
+    /// range check so an out-of-range integer is rejected rather than
+    /// silently constructing an invalid color.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let color = i32::deserialize(deserializer)?;
+        ClarionColor::new(color).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{ClarionColor, RgbColor};
@@ -114,4 +132,20 @@ mod tests {
         let c_color = ClarionColor::from(rgb_color);
         assert_eq!(c_color.color(), 16711680);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let c_color = ClarionColor::new(4259584).unwrap();
+        let json = serde_json::to_string(&c_color).unwrap();
+        let result: ClarionColor = serde_json::from_str(&json).unwrap();
+        assert_eq!(result.color(), c_color.color());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_out_of_range() {
+        let result: Result<ClarionColor, _> = serde_json::from_str("16777216");
+        assert!(result.is_err());
+    }
 }
@@ -1,12 +1,30 @@
 //! The [`ClarionDate`] struct and associated `impl`s.
 
-use time::{macros::date, Date, Duration};
+use std::ops::{Add, Sub};
+
+use time::{macros::date, Date, Duration, Month, Weekday};
 
 use crate::ClarionErr;
 
 /// The Clarion date epoch, represented by the 28th of December, 1800.
 pub const CLARION_EPOCH: Date = date!(1800 - 12 - 28);
 
+/// The offset that aligns `date.rem_euclid(7)` with `time::Weekday`, pinned
+/// by the fact that `CLARION_EPOCH` (day 0) falls on a Sunday.
+const WEEKDAY_EPOCH_OFFSET: i32 = 6;
+
+/// `time::Weekday` has no public "from index" constructor, so this mirrors
+/// its Monday-first ordering for `weekday_index -> Weekday` lookups.
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
 /// Defines a calendar date in the ClarionDate format - the number of days
 /// between the date and the 28th of December, 1800.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -16,6 +34,14 @@ pub struct ClarionDate {
 }
 
 impl ClarionDate {
+    /// The minimum `ClarionDate` day count that is representable as a
+    /// `time::Date`, relative to `Date::MIN`.
+    pub const MIN: i32 = -4_309_857;
+
+    /// The maximum `ClarionDate` day count that is representable as a
+    /// `time::Date`, relative to `Date::MAX`.
+    pub const MAX: i32 = 2_994_626;
+
     /// Creates a new `ClarionDate` with a specified number of days between the date
     /// and the 28th of December, 1800.
     ///
@@ -24,6 +50,44 @@ impl ClarionDate {
     /// let c_date = clarion::ClarionDate::new(80727);
     /// ```
     pub fn new(date: i32) -> Self {
+        ClarionDate::new_unchecked(date)
+    }
+
+    /// Creates a new `ClarionDate`, returning `ClarionErr::OutOfRange` if
+    /// the specified day count falls outside `ClarionDate::MIN` and
+    /// `ClarionDate::MAX`, the window that is guaranteed to round-trip
+    /// through `time::Date` without a later `TryFrom` failure.
+    ///
+    /// # Examples
+    ///
+    /// Valid date returns `Ok`:
+    /// ```
+    /// let c_date = clarion::ClarionDate::try_new(80727);
+    /// assert!(c_date.is_ok());
+    /// ```
+    ///
+    /// Invalid date returns `Err`:
+    /// ```
+    /// let c_date = clarion::ClarionDate::try_new(i32::MAX);
+    /// assert!(c_date.is_err());
+    /// ```
+    pub fn try_new(date: i32) -> Result<ClarionDate, ClarionErr> {
+        if (ClarionDate::MIN..=ClarionDate::MAX).contains(&date) {
+            Ok(ClarionDate { date })
+        } else {
+            Err(ClarionErr::OutOfRange)
+        }
+    }
+
+    /// Creates a new `ClarionDate` with a specified number of days between
+    /// the date and the 28th of December, 1800, without checking that the
+    /// day count is representable as a `time::Date`.
+    ///
+    /// # Examples
+    /// ```
+    /// let c_date = clarion::ClarionDate::new_unchecked(80727);
+    /// ```
+    pub fn new_unchecked(date: i32) -> Self {
         ClarionDate { date }
     }
 
@@ -39,6 +103,57 @@ impl ClarionDate {
     pub fn date(&self) -> i32 {
         self.date
     }
+
+    /// Get the `time::Weekday` of this `ClarionDate`, computed arithmetically
+    /// without constructing a `time::Date`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::ClarionDate;
+    /// use time::Weekday;
+    /// let c_date = ClarionDate::new(80727);
+    /// assert_eq!(c_date.weekday(), Weekday::Wednesday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        let index = (self.date.rem_euclid(7) + WEEKDAY_EPOCH_OFFSET) % 7;
+        WEEKDAYS[index as usize]
+    }
+
+    /// Get the calendar year of this `ClarionDate`.
+    ///
+    /// # Errors
+    /// Returns `ClarionErr::ConversionOverflowed` if this date overflows
+    /// `time::Date`.
+    pub fn year(&self) -> Result<i32, ClarionErr> {
+        Ok(Date::try_from(*self)?.year())
+    }
+
+    /// Get the calendar month of this `ClarionDate`.
+    ///
+    /// # Errors
+    /// Returns `ClarionErr::ConversionOverflowed` if this date overflows
+    /// `time::Date`.
+    pub fn month(&self) -> Result<Month, ClarionErr> {
+        Ok(Date::try_from(*self)?.month())
+    }
+
+    /// Get the day of the month of this `ClarionDate`.
+    ///
+    /// # Errors
+    /// Returns `ClarionErr::ConversionOverflowed` if this date overflows
+    /// `time::Date`.
+    pub fn day(&self) -> Result<u8, ClarionErr> {
+        Ok(Date::try_from(*self)?.day())
+    }
+
+    /// Get the day of the year of this `ClarionDate`.
+    ///
+    /// # Errors
+    /// Returns `ClarionErr::ConversionOverflowed` if this date overflows
+    /// `time::Date`.
+    pub fn ordinal(&self) -> Result<u16, ClarionErr> {
+        Ok(Date::try_from(*self)?.ordinal())
+    }
 }
 
 impl From<Date> for ClarionDate {
@@ -101,6 +216,78 @@ impl TryFrom<ClarionDate> for Date {
     }
 }
 
+impl Add<Duration> for ClarionDate {
+    type Output = ClarionDate;
+
+    /// Advance a `ClarionDate` by a `time::Duration`, truncating any
+    /// sub-day remainder.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::ClarionDate;
+    /// let c_date = ClarionDate::new(80727) + time::Duration::days(3);
+    /// assert_eq!(c_date.date(), 80730);
+    /// ```
+    fn add(self, rhs: Duration) -> Self::Output {
+        ClarionDate {
+            date: self.date + rhs.whole_days() as i32,
+        }
+    }
+}
+
+impl Sub<Duration> for ClarionDate {
+    type Output = ClarionDate;
+
+    /// Retreat a `ClarionDate` by a `time::Duration`, truncating any
+    /// sub-day remainder.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::ClarionDate;
+    /// let c_date = ClarionDate::new(80727) - time::Duration::days(3);
+    /// assert_eq!(c_date.date(), 80724);
+    /// ```
+    fn sub(self, rhs: Duration) -> Self::Output {
+        ClarionDate {
+            date: self.date - rhs.whole_days() as i32,
+        }
+    }
+}
+
+impl Sub<ClarionDate> for ClarionDate {
+    type Output = Duration;
+
+    /// Get the `time::Duration` between two `ClarionDate` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::ClarionDate;
+    /// let gap = ClarionDate::new(80730) - ClarionDate::new(80727);
+    /// assert_eq!(gap, time::Duration::days(3));
+    /// ```
+    fn sub(self, rhs: ClarionDate) -> Self::Output {
+        Duration::days((self.date - rhs.date) as i64)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ClarionDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.date)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ClarionDate {
+    /// Deserializes a `ClarionDate`, re-running the `ClarionDate::MIN..=MAX`
+    /// range check so an out-of-range integer is rejected rather than
+    /// silently constructing a date that later overflows `time::Date`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date = i32::deserialize(deserializer)?;
+        ClarionDate::try_new(date).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use time::{macros::date, Date};
@@ -191,4 +378,99 @@ mod tests {
         let date2: Date = result.try_into().unwrap();
         assert_eq!(date, date2);
     }
+
+    #[test]
+    fn add_duration() {
+        let date = ClarionDate::new(80727) + time::Duration::days(3);
+        assert_eq!(date.date(), 80730);
+    }
+
+    #[test]
+    fn sub_duration() {
+        let date = ClarionDate::new(80727) - time::Duration::days(3);
+        assert_eq!(date.date(), 80724);
+    }
+
+    #[test]
+    fn sub_clarion_date() {
+        let gap = ClarionDate::new(80730) - ClarionDate::new(80727);
+        assert_eq!(gap, time::Duration::days(3));
+    }
+
+    #[test]
+    fn weekday_epoch() {
+        assert_eq!(ClarionDate::new(0).weekday(), time::Weekday::Sunday);
+    }
+
+    #[test]
+    fn weekday_reference_date() {
+        assert_eq!(ClarionDate::new(80727).weekday(), time::Weekday::Wednesday);
+    }
+
+    #[test]
+    fn weekday_negative() {
+        assert_eq!(ClarionDate::new(-1).weekday(), time::Weekday::Saturday);
+    }
+
+    #[test]
+    fn year_month_day_ordinal() {
+        let c_date = ClarionDate::new(80727);
+        assert_eq!(c_date.year().unwrap(), 2022);
+        assert_eq!(c_date.month().unwrap(), time::Month::January);
+        assert_eq!(c_date.day().unwrap(), 5);
+        assert_eq!(c_date.ordinal().unwrap(), 5);
+    }
+
+    #[test]
+    fn year_month_day_ordinal_overflow() {
+        let c_date = ClarionDate::new(i32::MAX);
+        assert_eq!(c_date.year(), Err(ClarionErr::ConversionOverflowed));
+        assert_eq!(c_date.month(), Err(ClarionErr::ConversionOverflowed));
+        assert_eq!(c_date.day(), Err(ClarionErr::ConversionOverflowed));
+        assert_eq!(c_date.ordinal(), Err(ClarionErr::ConversionOverflowed));
+    }
+
+    #[test]
+    fn try_new_in_range() {
+        assert!(ClarionDate::try_new(80727).is_ok());
+    }
+
+    #[test]
+    fn try_new_out_of_range_above() {
+        assert_eq!(
+            ClarionDate::try_new(ClarionDate::MAX + 1),
+            Err(ClarionErr::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn try_new_out_of_range_below() {
+        assert_eq!(
+            ClarionDate::try_new(ClarionDate::MIN - 1),
+            Err(ClarionErr::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn try_new_bounds_round_trip() {
+        assert!(Date::try_from(ClarionDate::try_new(ClarionDate::MIN).unwrap()).is_ok());
+        assert!(Date::try_from(ClarionDate::try_new(ClarionDate::MAX).unwrap()).is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let c_date = ClarionDate::new(80727);
+        let json = serde_json::to_string(&c_date).unwrap();
+        let result: ClarionDate = serde_json::from_str(&json).unwrap();
+        assert_eq!(result, c_date);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_out_of_range() {
+        let json = (ClarionDate::MAX as i64 + 1).to_string();
+        let result: Result<ClarionDate, _> = serde_json::from_str(&json);
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,180 @@
+//! The [`ClarionDateTime`] struct and associated `impl`s.
+use std::ops::{Add, Sub};
+
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{ClarionDate, ClarionErr, ClarionTime};
+
+/// Defines a single instant as a Clarion date paired with a Clarion time,
+/// mirroring `time::PrimitiveDateTime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ClarionDateTime {
+    /// The number of days between this date and the 28th of December, 1800.
+    date: ClarionDate,
+    /// The number of centiseconds between this time and midnight.
+    time: ClarionTime,
+}
+
+impl ClarionDateTime {
+    /// Creates a new `ClarionDateTime` from a `ClarionDate` and a `ClarionTime`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{ClarionDate, ClarionDateTime, ClarionTime};
+    /// let date = ClarionDate::new(80727);
+    /// let time = ClarionTime::new(5964000).unwrap();
+    /// let date_time = ClarionDateTime::new(date, time);
+    /// assert_eq!(date_time.date(), date);
+    /// assert_eq!(date_time.time(), time);
+    /// ```
+    pub fn new(date: ClarionDate, time: ClarionTime) -> Self {
+        ClarionDateTime { date, time }
+    }
+
+    /// Get the `ClarionDate` half of this `ClarionDateTime`.
+    pub fn date(&self) -> ClarionDate {
+        self.date
+    }
+
+    /// Get the `ClarionTime` half of this `ClarionDateTime`.
+    pub fn time(&self) -> ClarionTime {
+        self.time
+    }
+}
+
+impl From<PrimitiveDateTime> for ClarionDateTime {
+    /// Convert a `time::PrimitiveDateTime` value into a `ClarionDateTime` value.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::ClarionDateTime;
+    /// let date_time = time::macros::datetime!(2022-01-05 16:34:00);
+    /// let c_date_time = ClarionDateTime::from(date_time);
+    /// assert_eq!(c_date_time.date().date(), 80727);
+    /// assert_eq!(c_date_time.time().time(), 5964000);
+    /// ```
+    fn from(date_time: PrimitiveDateTime) -> Self {
+        ClarionDateTime {
+            date: ClarionDate::from(date_time.date()),
+            time: ClarionTime::from(date_time.time()),
+        }
+    }
+}
+
+impl TryFrom<ClarionDateTime> for PrimitiveDateTime {
+    type Error = ClarionErr;
+    /// Convert a `ClarionDateTime` into a `time::PrimitiveDateTime` value.
+    ///
+    /// Propagates `ClarionErr::ConversionOverflowed` if the date half of
+    /// the `ClarionDateTime` overflows `time::Date`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{ClarionDate, ClarionDateTime, ClarionTime};
+    /// let date = ClarionDate::new(80727);
+    /// let time = ClarionTime::new(5964000).unwrap();
+    /// let date_time: time::PrimitiveDateTime =
+    ///     ClarionDateTime::new(date, time).try_into().unwrap();
+    /// assert_eq!(date_time, time::macros::datetime!(2022-01-05 16:34:00));
+    /// ```
+    fn try_from(value: ClarionDateTime) -> Result<Self, Self::Error> {
+        let date = time::Date::try_from(value.date)?;
+        let time = time::Time::from(value.time);
+        Ok(PrimitiveDateTime::new(date, time))
+    }
+}
+
+impl Add<Duration> for ClarionDateTime {
+    type Output = ClarionDateTime;
+
+    /// Add a `time::Duration` to a `ClarionDateTime`, carrying any day
+    /// roll-over from the time component into the date component.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{ClarionDate, ClarionDateTime, ClarionTime};
+    /// let date_time = ClarionDateTime::new(ClarionDate::new(0), ClarionTime::new(0).unwrap())
+    ///     + time::Duration::hours(25);
+    /// assert_eq!(date_time.date(), ClarionDate::new(1));
+    /// assert_eq!(date_time.time(), ClarionTime::new(360_000).unwrap());
+    /// ```
+    fn add(self, rhs: Duration) -> Self::Output {
+        let centiseconds = self.time.time() as i128 + rhs.whole_milliseconds() / 10;
+        let day_offset = centiseconds.div_euclid(8_640_000i128) as i32;
+        ClarionDateTime {
+            date: self.date + Duration::days(day_offset as i64),
+            time: ClarionTime::new_wrapping(centiseconds.rem_euclid(8_640_000i128) as i32),
+        }
+    }
+}
+
+impl Sub<Duration> for ClarionDateTime {
+    type Output = ClarionDateTime;
+
+    /// Subtract a `time::Duration` from a `ClarionDateTime`, carrying any
+    /// day roll-over from the time component into the date component.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{ClarionDate, ClarionDateTime, ClarionTime};
+    /// let date_time = ClarionDateTime::new(ClarionDate::new(1), ClarionTime::new(0).unwrap())
+    ///     - time::Duration::hours(1);
+    /// assert_eq!(date_time.date(), ClarionDate::new(0));
+    /// assert_eq!(date_time.time(), ClarionTime::new(8_280_000).unwrap());
+    /// ```
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use crate::{ClarionDate, ClarionDateTime, ClarionErr, ClarionTime};
+
+    #[test]
+    fn date_time_to_primitive_date_time() {
+        let date_time = ClarionDateTime::new(ClarionDate::new(80727), ClarionTime::new(5964000).unwrap());
+        let result: time::PrimitiveDateTime = date_time.try_into().unwrap();
+        assert_eq!(result, datetime!(2022-01-05 16:34:00));
+    }
+
+    #[test]
+    fn primitive_date_time_to_date_time() {
+        let date_time = datetime!(2022-01-05 16:34:00);
+        let result: ClarionDateTime = date_time.into();
+        assert_eq!(result, ClarionDateTime::new(ClarionDate::new(80727), ClarionTime::new(5964000).unwrap()));
+    }
+
+    #[test]
+    fn date_time_overflow() {
+        let date_time = ClarionDateTime::new(ClarionDate::new(i32::MAX), ClarionTime::new(0).unwrap());
+        let result: Result<time::PrimitiveDateTime, ClarionErr> = date_time.try_into();
+        assert_eq!(result, Err(ClarionErr::ConversionOverflowed));
+    }
+
+    #[test]
+    fn add_duration_rolls_over_to_next_day() {
+        let date_time = ClarionDateTime::new(ClarionDate::new(0), ClarionTime::new(0).unwrap())
+            + time::Duration::hours(25);
+        assert_eq!(date_time.date(), ClarionDate::new(1));
+        assert_eq!(date_time.time(), ClarionTime::new(360_000).unwrap());
+    }
+
+    #[test]
+    fn sub_duration_rolls_back_to_previous_day() {
+        let date_time = ClarionDateTime::new(ClarionDate::new(1), ClarionTime::new(0).unwrap())
+            - time::Duration::hours(1);
+        assert_eq!(date_time.date(), ClarionDate::new(0));
+        assert_eq!(date_time.time(), ClarionTime::new(8_280_000).unwrap());
+    }
+
+    #[test]
+    fn date_time_reversibility() {
+        let date_time = datetime!(2020-06-30 17:30:43);
+        let result: ClarionDateTime = date_time.into();
+        let date_time2: time::PrimitiveDateTime = result.try_into().unwrap();
+        assert_eq!(date_time, date_time2);
+    }
+}
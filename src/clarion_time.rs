@@ -1,7 +1,15 @@
 #![warn(missing_docs)]
+use std::ops::{Add, Sub};
+
 use time::Duration;
 use time::Time;
 
+use crate::ClarionErr;
+
+/// The number of centiseconds in a single day, and the exclusive upper
+/// bound of a valid `ClarionTime` value.
+const CENTISECONDS_PER_DAY: i32 = 8_640_000;
+
 /// Defines a moment in time in the Clarion time format, the number of
 /// centiseconds between the time and midnight.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -11,17 +19,69 @@ pub struct ClarionTime {
 }
 
 impl ClarionTime {
-    /// Creates a new `ClarionTime` with a specified number of centiseconds between
-    /// the time and midnight.
+    /// The minimum valid representation of a `ClarionTime`, midnight.
+    pub const MIN: i32 = 0;
+
+    /// The maximum valid representation of a `ClarionTime`, one centisecond
+    /// before midnight.
+    pub const MAX: i32 = CENTISECONDS_PER_DAY - 1;
+
+    /// Creates a new `ClarionTime` with a specified number of centiseconds
+    /// between the time and midnight.
+    ///
+    /// The specified time must be between `ClarionTime::MIN` and
+    /// `ClarionTime::MAX`. Times outside of these bounds will return an
+    /// `Err` result with `ClarionErr::OutOfRange`.
     ///
     /// # Examples
+    ///
+    /// Valid time returns `Ok`:
     /// ```
     /// let c_time = clarion::ClarionTime::new(5964000);
+    /// assert!(c_time.is_ok());
+    /// ```
+    ///
+    /// Invalid time returns `Err`:
+    /// ```
+    /// let c_time = clarion::ClarionTime::new(-1);
+    /// assert!(c_time.is_err());
     /// ```
-    pub fn new(time: i32) -> Self {
-        // The time cannot exceed the total number of centiseconds in 24 hours.
-        let time = time % 8640000;
-        ClarionTime { time }
+    pub fn new(time: i32) -> Result<ClarionTime, ClarionErr> {
+        if (ClarionTime::MIN..=ClarionTime::MAX).contains(&time) {
+            Ok(ClarionTime { time })
+        } else {
+            Err(ClarionErr::OutOfRange)
+        }
+    }
+
+    /// Creates a new `ClarionTime`, normalizing any `i32` into the valid
+    /// centisecond range by wrapping around midnight.
+    ///
+    /// Unlike `new`, this never fails: out-of-range input is wrapped using
+    /// Euclidean remainder, so negative input wraps forward from midnight
+    /// (e.g. `-1` becomes the centisecond before midnight).
+    ///
+    /// # Examples
+    /// ```
+    /// let c_time = clarion::ClarionTime::new_wrapping(-1);
+    /// assert_eq!(c_time.time(), 8_639_999);
+    /// ```
+    pub fn new_wrapping(time: i32) -> ClarionTime {
+        ClarionTime {
+            time: time.rem_euclid(CENTISECONDS_PER_DAY),
+        }
+    }
+
+    /// Creates a new `ClarionTime`, returning `None` if the specified time
+    /// is outside `ClarionTime::MIN` and `ClarionTime::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// assert!(clarion::ClarionTime::new_opt(5964000).is_some());
+    /// assert!(clarion::ClarionTime::new_opt(-1).is_none());
+    /// ```
+    pub fn new_opt(time: i32) -> Option<ClarionTime> {
+        ClarionTime::new(time).ok()
     }
 
     /// Get the integral value representing this ClarionTime value.
@@ -52,14 +112,14 @@ impl From<time::Time> for ClarionTime {
     /// ```
     /// Using `into()`
     /// ```
-    /// let c_time = clarion::ClarionTime::new(5964000);
+    /// let c_time = clarion::ClarionTime::new(5964000).unwrap();
     /// let time: time::Time = c_time.into();
     /// assert_eq!(time, time::macros::time!(16:34:00))
     /// ```
     fn from(time: time::Time) -> Self {
-        ClarionTime {
-            time: ((time - Time::MIDNIGHT).whole_milliseconds() / 10) as i32,
-        }
+        // Always within `0..CENTISECONDS_PER_DAY`, but normalized through
+        // `new_wrapping` so the invariant is enforced in one place.
+        ClarionTime::new_wrapping(((time - Time::MIDNIGHT).whole_milliseconds() / 10) as i32)
     }
 }
 
@@ -70,7 +130,7 @@ impl From<ClarionTime> for time::Time {
     /// ```
     /// use clarion::ClarionTime;
     /// use time::macros::time;
-    /// let time = time::Time::from(ClarionTime::new(5964000));
+    /// let time = time::Time::from(ClarionTime::new(5964000).unwrap());
     /// assert_eq!(time, time!(16:34:00));
     /// ```
     fn from(value: ClarionTime) -> Self {
@@ -79,43 +139,115 @@ impl From<ClarionTime> for time::Time {
     }
 }
 
+impl Add<Duration> for ClarionTime {
+    type Output = ClarionTime;
+
+    /// Add a `time::Duration` to a `ClarionTime`, wrapping around midnight
+    /// if the centisecond count leaves the valid day range.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::ClarionTime;
+    /// let c_time = ClarionTime::new(0).unwrap() + time::Duration::seconds(-1);
+    /// assert_eq!(c_time.time(), 8_639_900);
+    /// ```
+    fn add(self, rhs: Duration) -> Self::Output {
+        let centiseconds = self.time as i128 + rhs.whole_milliseconds() / 10;
+        ClarionTime::new_wrapping(centiseconds.rem_euclid(CENTISECONDS_PER_DAY as i128) as i32)
+    }
+}
+
+impl Sub<Duration> for ClarionTime {
+    type Output = ClarionTime;
+
+    /// Subtract a `time::Duration` from a `ClarionTime`, wrapping around
+    /// midnight if the centisecond count leaves the valid day range.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::ClarionTime;
+    /// let c_time = ClarionTime::new(0).unwrap() - time::Duration::seconds(1);
+    /// assert_eq!(c_time.time(), 8_639_900);
+    /// ```
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ClarionTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.time)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ClarionTime {
+    /// Deserializes a `ClarionTime`, re-normalizing the centisecond count
+    /// into the valid day range via `ClarionTime::new_wrapping`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let time = i32::deserialize(deserializer)?;
+        Ok(ClarionTime::new_wrapping(time))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use time::{macros::time, Time};
+    use time::{macros::time, Duration, Time};
+
+    use crate::{ClarionErr, ClarionTime};
+
+    #[test]
+    fn new_out_of_range_below() {
+        assert_eq!(ClarionTime::new(-1), Err(ClarionErr::OutOfRange));
+    }
+
+    #[test]
+    fn new_out_of_range_above() {
+        assert_eq!(ClarionTime::new(8_640_000), Err(ClarionErr::OutOfRange));
+    }
+
+    #[test]
+    fn new_in_range() {
+        assert!(ClarionTime::new(5964000).is_ok());
+    }
 
-    use crate::ClarionTime;
+    #[test]
+    fn new_opt_out_of_range() {
+        assert_eq!(ClarionTime::new_opt(i32::MAX), None);
+    }
 
     #[test]
     fn ctime_to_time_i32_max() {
-        let time = ClarionTime::new(i32::MAX);
+        let time = ClarionTime::new_wrapping(i32::MAX);
         let result: Time = time.into();
         assert_eq!(result, time!(13:13:56.47));
     }
 
     #[test]
     fn ctime_to_time_i32_min() {
-        let time = ClarionTime::new(i32::MIN);
+        let time = ClarionTime::new_wrapping(i32::MIN);
         let result: Time = time.into();
         assert_eq!(result, time!(10:46:03.52));
     }
 
     #[test]
     fn ctime_to_time_zero() {
-        let time = ClarionTime::new(0);
+        let time = ClarionTime::new(0).unwrap();
         let result: Time = time.into();
         assert_eq!(result, time!(00:00:00));
     }
 
     #[test]
     fn ctime_to_time_one() {
-        let time = ClarionTime::new(1);
+        let time = ClarionTime::new(1).unwrap();
         let result: Time = time.into();
         assert_eq!(result, time!(00:00:00.01));
     }
 
     #[test]
     fn ctime_to_time_negative_one() {
-        let time = ClarionTime::new(-1);
+        let time = ClarionTime::new_wrapping(-1);
         let result: Time = time.into();
         assert_eq!(result, time!(23:59:59.99));
     }
@@ -127,4 +259,32 @@ mod tests {
         let time2 = result.into();
         assert_eq!(time, time2);
     }
+
+    #[test]
+    fn add_duration() {
+        let time = ClarionTime::new(0).unwrap() + Duration::seconds(-1);
+        assert_eq!(time.time(), 8_639_900);
+    }
+
+    #[test]
+    fn sub_duration() {
+        let time = ClarionTime::new(0).unwrap() - Duration::seconds(1);
+        assert_eq!(time.time(), 8_639_900);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let c_time = ClarionTime::new(5964000).unwrap();
+        let json = serde_json::to_string(&c_time).unwrap();
+        let result: ClarionTime = serde_json::from_str(&json).unwrap();
+        assert_eq!(result, c_time);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_normalizes_out_of_range() {
+        let result: ClarionTime = serde_json::from_str("-1").unwrap();
+        assert_eq!(result.time(), 8_639_999);
+    }
 }
@@ -3,7 +3,7 @@
 
 use time::{macros::date, Date, Duration};
 
-use crate::cserr::CSErr;
+use crate::cserr::{CSErr, RangeError};
 
 /// The CSTime date epoch, represented by the 28th of December, 1800.
 pub const CSTIME_EPOCH: Date = date!(1800 - 12 - 28);
@@ -17,17 +17,44 @@ pub struct CSDate {
 }
 
 impl CSDate {
+    /// The minimum `CSDate` day count that is representable as a
+    /// `time::Date`, relative to `Date::MIN`.
+    pub const MIN: i32 = -4_309_857;
+
+    /// The maximum `CSDate` day count that is representable as a
+    /// `time::Date`, relative to `Date::MAX`.
+    pub const MAX: i32 = 2_994_626;
+
     /// Creates a new `CSDate` with a specified number of days between the date
     /// and the 28th of December, 1800.
-    /// 
+    ///
     /// # Examples
     /// ```
-    /// let cs_date = cstime::CSDate { date: 80727 };
+    /// let cs_date = clarion::CSDate { date: 80727 };
     /// assert_eq!(cs_date.date, 80727);
     /// ```
     pub fn new(date: i32) -> Self {
         CSDate { date }
     }
+
+    /// Creates a new `CSDate`, returning a `RangeError` if the specified day
+    /// count falls outside `CSDate::MIN` and `CSDate::MAX`, the window that
+    /// is guaranteed to round-trip through `time::Date` without a later
+    /// `TryFrom` failure.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSDate;
+    /// assert!(CSDate::try_new(80727).is_ok());
+    /// assert!(CSDate::try_new(i32::MAX).is_err());
+    /// ```
+    pub fn try_new(date: i32) -> Result<CSDate, RangeError> {
+        if (CSDate::MIN..=CSDate::MAX).contains(&date) {
+            Ok(CSDate { date })
+        } else {
+            Err(RangeError::new("CSDate", "date"))
+        }
+    }
 }
 
 impl From<Date> for CSDate {
@@ -37,13 +64,13 @@ impl From<Date> for CSDate {
     /// Using `from()`
     /// ```
     /// let date = time::macros::date!(2022-01-05);
-    /// let cs_date = cstime::CSDate::from(date);
+    /// let cs_date = clarion::CSDate::from(date);
     /// assert_eq!(cs_date.date, 80727);
     /// ```
     /// Using `into()`:
     /// ```
     /// let date = time::macros::date!(2022-01-05);
-    /// let cs_date:cstime::CSDate = date.into();
+    /// let cs_date:clarion::CSDate = date.into();
     /// assert_eq!(cs_date.date, 80727);
     /// ```
     fn from(date: Date) -> Self {
@@ -61,14 +88,14 @@ impl TryFrom<CSDate> for Date {
     /// Using `try_from()`
     /// ```
     /// use time::Date;
-    /// use cstime::{CSDate, CSErr};
+    /// use clarion::{CSDate, CSErr};
     /// let cs_date = CSDate::new(80727);
     /// let date: Result<Date, CSErr> = Date::try_from(cs_date);
     /// assert_eq!(date, Ok(time::macros::date!(2022-01-05)));
     /// ```
     /// Using `try_into()`
     /// ```
-    /// use cstime::CSDate;
+    /// use clarion::CSDate;
     /// let cs_date = CSDate::new(80727);
     /// let date: time::Date = cs_date.try_into().unwrap();
     /// let cmp_date = time::macros::date!(2022-01-05);
@@ -76,7 +103,7 @@ impl TryFrom<CSDate> for Date {
     /// ```
     /// Using `try_from()` with `expect()` clause.
     /// ```
-    /// let cs_date = cstime::CSDate::new(80727);
+    /// let cs_date = clarion::CSDate::new(80727);
     /// let date = time::Date::try_from(cs_date)
     ///     .expect("The input value produce a valid date between Date::MAX and Date::MIN.");
     /// assert_eq!(date, time::macros::date!(2022-01-05));
@@ -96,6 +123,34 @@ mod tests {
 
     use crate::{csdate::CSDate, cserr::CSErr};
 
+    #[test]
+    fn csdate_day_four_is_new_years_day_1801() {
+        let date = CSDate::new(4);
+        let result: Date = date.try_into().unwrap();
+        assert_eq!(result, date!(1801 - 01 - 01));
+    }
+
+    #[test]
+    fn try_new_in_range() {
+        assert!(CSDate::try_new(80727).is_ok());
+    }
+
+    #[test]
+    fn try_new_out_of_range_above() {
+        assert!(CSDate::try_new(CSDate::MAX + 1).is_err());
+    }
+
+    #[test]
+    fn try_new_out_of_range_below() {
+        assert!(CSDate::try_new(CSDate::MIN - 1).is_err());
+    }
+
+    #[test]
+    fn try_new_bounds_round_trip() {
+        assert!(Date::try_from(CSDate::try_new(CSDate::MIN).unwrap()).is_ok());
+        assert!(Date::try_from(CSDate::try_new(CSDate::MAX).unwrap()).is_ok());
+    }
+
     #[test]
     fn date_i32_max() {
         let cs_date = CSDate { date: i32::MAX };
@@ -0,0 +1,195 @@
+#![warn(missing_docs)]
+//! The [`CSDateTime`] struct and associated `impl`s.
+
+use crate::cserr::CSErr;
+use crate::csdate::CSDate;
+use crate::cstime::CSTime;
+
+/// Defines a single instant as a `CSDate` paired with a `CSTime`, mirroring
+/// `time::PrimitiveDateTime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CSDateTime {
+    /// The number of days between this date and the 28th of December, 1800.
+    pub date: CSDate,
+    /// The number of centiseconds between this time and midnight.
+    pub time: CSTime,
+}
+
+impl CSDateTime {
+    /// Creates a new `CSDateTime` from a `CSDate` and a `CSTime`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{CSDate, CSDateTime, CSTime};
+    /// let date_time = CSDateTime::new(CSDate::new(80727), CSTime::new(5964000));
+    /// assert_eq!(date_time.date, CSDate::new(80727));
+    /// assert_eq!(date_time.time, CSTime::new(5964000));
+    /// ```
+    pub fn new(date: CSDate, time: CSTime) -> Self {
+        CSDateTime { date, time }
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for CSDateTime {
+    /// Convert a `time::PrimitiveDateTime` value into a `CSDateTime` value.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSDateTime;
+    /// let date_time = time::macros::datetime!(2022-01-05 16:34:00);
+    /// let cs_date_time = CSDateTime::from(date_time);
+    /// assert_eq!(cs_date_time.date.date, 80727);
+    /// assert_eq!(cs_date_time.time.time, 5964000);
+    /// ```
+    fn from(date_time: time::PrimitiveDateTime) -> Self {
+        CSDateTime {
+            date: CSDate::from(date_time.date()),
+            time: CSTime::from(date_time.time()),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<CSDateTime> for time::PrimitiveDateTime {
+    type Error = CSErr;
+
+    /// Convert a `CSDateTime` into a `time::PrimitiveDateTime` value.
+    ///
+    /// Propagates `CSErr::ConversionOverflowed` if the date half of the
+    /// `CSDateTime` overflows `time::Date`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{CSDate, CSDateTime, CSTime};
+    /// let date_time: time::PrimitiveDateTime =
+    ///     CSDateTime::new(CSDate::new(80727), CSTime::new(5964000))
+    ///         .try_into()
+    ///         .unwrap();
+    /// assert_eq!(date_time, time::macros::datetime!(2022-01-05 16:34:00));
+    /// ```
+    fn try_from(value: CSDateTime) -> Result<Self, Self::Error> {
+        let date = time::Date::try_from(value.date)?;
+        let time = time::Time::from(value.time);
+        Ok(time::PrimitiveDateTime::new(date, time))
+    }
+}
+
+#[cfg(feature = "time")]
+impl std::ops::Add<time::Duration> for CSDateTime {
+    type Output = CSDateTime;
+
+    /// Add a `time::Duration` to a `CSDateTime`, carrying any day roll-over
+    /// from the time component into the date component.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{CSDate, CSDateTime, CSTime};
+    /// let date_time =
+    ///     CSDateTime::new(CSDate::new(0), CSTime::new(0)) + time::Duration::hours(25);
+    /// assert_eq!(date_time.date, CSDate::new(1));
+    /// assert_eq!(date_time.time, CSTime::new(360_000));
+    /// ```
+    fn add(self, rhs: time::Duration) -> Self::Output {
+        let centiseconds = self.time.time as i128 + rhs.whole_milliseconds() / 10;
+        let day_offset = centiseconds.div_euclid(8_640_000) as i32;
+        CSDateTime {
+            date: CSDate::new(self.date.date + day_offset),
+            time: CSTime::new(centiseconds.rem_euclid(8_640_000) as i32),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl std::ops::Sub<time::Duration> for CSDateTime {
+    type Output = CSDateTime;
+
+    /// Subtract a `time::Duration` from a `CSDateTime`, carrying any day
+    /// roll-over from the time component into the date component.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::{CSDate, CSDateTime, CSTime};
+    /// let date_time = CSDateTime::new(CSDate::new(1), CSTime::new(0)) - time::Duration::hours(1);
+    /// assert_eq!(date_time.date, CSDate::new(0));
+    /// assert_eq!(date_time.time, CSTime::new(8_280_000));
+    /// ```
+    fn sub(self, rhs: time::Duration) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "time")]
+    use time::macros::datetime;
+
+    use crate::{csdate::CSDate, csdatetime::CSDateTime, cstime::CSTime};
+
+    #[cfg(feature = "time")]
+    use crate::cserr::CSErr;
+
+    #[test]
+    fn new_pairs_date_and_time() {
+        let date_time = CSDateTime::new(CSDate::new(80727), CSTime::new(5964000));
+        assert_eq!(date_time.date.date, 80727);
+        assert_eq!(date_time.time.time, 5964000);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn date_time_to_primitive_date_time() {
+        let date_time = CSDateTime::new(CSDate::new(80727), CSTime::new(5964000));
+        let result: time::PrimitiveDateTime = date_time.try_into().unwrap();
+        assert_eq!(result, datetime!(2022-01-05 16:34:00));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn primitive_date_time_to_date_time() {
+        let date_time = datetime!(2022-01-05 16:34:00);
+        let result: CSDateTime = date_time.into();
+        assert_eq!(result, CSDateTime::new(CSDate::new(80727), CSTime::new(5964000)));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn date_time_overflow() {
+        let date_time = CSDateTime::new(CSDate::new(i32::MAX), CSTime::new(0));
+        let result: Result<time::PrimitiveDateTime, CSErr> = date_time.try_into();
+        assert_eq!(result, Err(CSErr::ConversionOverflowed));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn date_time_reversibility() {
+        let date_time = datetime!(2020-06-30 17:30:43);
+        let result: CSDateTime = date_time.into();
+        let date_time2: time::PrimitiveDateTime = result.try_into().unwrap();
+        assert_eq!(date_time, date_time2);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn clarion_day_four_anchor() {
+        let date_time = CSDateTime::new(CSDate::new(4), CSTime::new(0));
+        let result: time::PrimitiveDateTime = date_time.try_into().unwrap();
+        assert_eq!(result, datetime!(1801-01-01 00:00:00));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn add_duration_rolls_over_to_next_day() {
+        let date_time = CSDateTime::new(CSDate::new(0), CSTime::new(0)) + time::Duration::hours(25);
+        assert_eq!(date_time.date, CSDate::new(1));
+        assert_eq!(date_time.time, CSTime::new(360_000));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn sub_duration_rolls_back_to_previous_day() {
+        let date_time = CSDateTime::new(CSDate::new(1), CSTime::new(0)) - time::Duration::hours(1);
+        assert_eq!(date_time.date, CSDate::new(0));
+        assert_eq!(date_time.time, CSTime::new(8_280_000));
+    }
+}
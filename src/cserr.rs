@@ -1,7 +1,15 @@
 #![warn(missing_docs)]
-//! The [`CSErr`] enum
+//! The [`CSErr`] enum and [`RangeError`] struct.
+//!
+//! `CSErr` and `RangeError` only carry `&'static str`/enum data, so they
+//! have no `std` dependency beyond the optional `std::error::Error` impl.
+//! `ParseError` wraps an owned `String` message and is only available with
+//! the `std` feature, matching `CSTime::parse`/`CSTime::from_str`.
 
-use std::{error::Error, fmt::Display};
+use core::fmt::Display;
+
+#[cfg(feature = "std")]
+use std::error::Error;
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 /// Defines error states for the `cstime` library.
@@ -11,7 +19,7 @@ pub enum CSErr {
 }
 
 impl Display for CSErr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             CSErr::ConversionOverflowed => write!(
                 f,
@@ -22,4 +30,62 @@ impl Display for CSErr {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for CSErr {}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+/// An error returned when a `CSTime` or `CSDate` constructor is given a
+/// component, or a raw centisecond/day count, outside its valid range.
+pub struct RangeError {
+    type_name: &'static str,
+    component: &'static str,
+}
+
+impl RangeError {
+    pub(crate) fn new(type_name: &'static str, component: &'static str) -> Self {
+        RangeError {
+            type_name,
+            component,
+        }
+    }
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "the `{}` component was out of range for {}",
+            self.component, self.type_name
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for RangeError {}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Eq, PartialEq, Debug)]
+/// An error returned when `CSTime::parse` or `CSTime::from_str` cannot
+/// parse a string under a given format.
+pub struct ParseError {
+    message: String,
+}
+
+#[cfg(feature = "std")]
+impl ParseError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseError {}
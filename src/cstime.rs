@@ -1,6 +1,18 @@
 #![warn(missing_docs)]
-use time::Duration;
-use time::Time;
+//! `CSTime` itself, `try_new`/`normalize`/`from_hms_cs`, and the component
+//! accessors (`hour`/`minute`/`second`/`centisecond`) depend only on `core`
+//! and are available with no features enabled. String formatting/parsing
+//! (`format`/`parse`, `Display`/`FromStr`) need the `std` feature for
+//! `String`; the `time`-crate conversions and `Duration` arithmetic need
+//! the `time` feature; `serde` support needs both `serde` and `std`.
+use core::ops::{Add, Sub};
+
+#[cfg(feature = "time")]
+use time::{Duration, Time};
+
+#[cfg(feature = "std")]
+use crate::cserr::ParseError;
+use crate::cserr::RangeError;
 
 /// Defines a moment in time in the CSTime time format, the number of between
 /// the time and midnight.
@@ -16,27 +28,263 @@ impl CSTime {
     /// 
     /// # Examples
     /// ```
-    /// let cs_time = cstime::CSTime::new(5964000);
+    /// let cs_time = clarion::CSTime::new(5964000);
     /// ```
     pub fn new(time: i32) -> Self {
         CSTime { time }
     }
+
+    /// Creates a new `CSTime`, returning a `RangeError` if the specified
+    /// number of centiseconds falls outside a single day, `0..8_640_000`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// assert!(CSTime::try_new(5964000).is_ok());
+    /// assert!(CSTime::try_new(i32::MAX).is_err());
+    /// ```
+    pub fn try_new(time: i32) -> Result<CSTime, RangeError> {
+        if (0..8_640_000).contains(&time) {
+            Ok(CSTime { time })
+        } else {
+            Err(RangeError::new("CSTime", "time"))
+        }
+    }
+
+    /// Reduces this `CSTime` into its canonical `0..8_640_000` day range,
+    /// using Euclidean modulo so negative values wrap forward from midnight.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// assert_eq!(CSTime::new(-1).normalize().time, 8_639_999);
+    /// ```
+    pub fn normalize(self) -> CSTime {
+        CSTime {
+            time: self.time.rem_euclid(8_640_000),
+        }
+    }
+
+    /// Creates a new `CSTime` from its hour, minute, second, and centisecond
+    /// components, validating each against its own valid range.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// let cs_time = CSTime::from_hms_cs(16, 34, 0, 0).unwrap();
+    /// assert_eq!(cs_time.time, 5964000);
+    /// assert!(CSTime::from_hms_cs(24, 0, 0, 0).is_err());
+    /// ```
+    pub fn from_hms_cs(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        centisecond: u8,
+    ) -> Result<CSTime, RangeError> {
+        if hour >= 24 {
+            return Err(RangeError::new("CSTime", "hour"));
+        }
+        if minute >= 60 {
+            return Err(RangeError::new("CSTime", "minute"));
+        }
+        if second >= 60 {
+            return Err(RangeError::new("CSTime", "second"));
+        }
+        if centisecond >= 100 {
+            return Err(RangeError::new("CSTime", "centisecond"));
+        }
+        let time = hour as i32 * 360_000 + minute as i32 * 6_000 + second as i32 * 100 + centisecond as i32;
+        Ok(CSTime { time })
+    }
+
+    /// Get the hour component of this `CSTime`, `0..24`.
+    ///
+    /// Computed directly from the raw centisecond count, with no
+    /// dependency on the `time` crate.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// assert_eq!(CSTime::new(5964000).hour(), 16);
+    /// ```
+    pub fn hour(&self) -> u8 {
+        (self.normalize().time / 360_000) as u8
+    }
+
+    /// Get the minute component of this `CSTime`, `0..60`.
+    ///
+    /// Computed directly from the raw centisecond count, with no
+    /// dependency on the `time` crate.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// assert_eq!(CSTime::new(5964000).minute(), 34);
+    /// ```
+    pub fn minute(&self) -> u8 {
+        (self.normalize().time / 6_000 % 60) as u8
+    }
+
+    /// Get the second component of this `CSTime`, `0..60`.
+    ///
+    /// Computed directly from the raw centisecond count, with no
+    /// dependency on the `time` crate.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// assert_eq!(CSTime::new(5964000).second(), 0);
+    /// ```
+    pub fn second(&self) -> u8 {
+        (self.normalize().time / 100 % 60) as u8
+    }
+
+    /// Get the centisecond component of this `CSTime`, `0..100`.
+    ///
+    /// Computed directly from the raw centisecond count, with no
+    /// dependency on the `time` crate.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// assert_eq!(CSTime::new(5964050).centisecond(), 50);
+    /// ```
+    pub fn centisecond(&self) -> u8 {
+        (self.normalize().time % 100) as u8
+    }
+
+    /// Renders this `CSTime` using a strftime-style format string.
+    ///
+    /// Supports `%H` (hour), `%M` (minute), `%S` (second), and `%@`
+    /// (centisecond); any other character, including an unrecognized
+    /// specifier, is copied through verbatim.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// let cs_time = CSTime::new(5964000);
+    /// assert_eq!(cs_time.format("%H:%M:%S.%@"), "16:34:00.00");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn format(&self, fmt: &str) -> String {
+        let mut result = String::new();
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('H') => result.push_str(&format!("{:02}", self.hour())),
+                Some('M') => result.push_str(&format!("{:02}", self.minute())),
+                Some('S') => result.push_str(&format!("{:02}", self.second())),
+                Some('@') => result.push_str(&format!("{:02}", self.centisecond())),
+                Some(other) => {
+                    result.push('%');
+                    result.push(other);
+                }
+                None => result.push('%'),
+            }
+        }
+        result
+    }
+
+    /// Parses a `CSTime` from a string under a strftime-style format,
+    /// supporting the same `%H`, `%M`, `%S`, and `%@` specifiers as
+    /// `format`. Fields missing from the format default to zero, and
+    /// overflowing component values are rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// let cs_time = CSTime::parse("16:34:00.00", "%H:%M:%S.%@").unwrap();
+    /// assert_eq!(cs_time.time, 5964000);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse(input: &str, fmt: &str) -> Result<CSTime, ParseError> {
+        let mut hour = 0u8;
+        let mut minute = 0u8;
+        let mut second = 0u8;
+        let mut centisecond = 0u8;
+
+        let mut remaining = input;
+        let mut fmt_chars = fmt.chars();
+        while let Some(fc) = fmt_chars.next() {
+            if fc != '%' {
+                if remaining.starts_with(fc) {
+                    remaining = &remaining[fc.len_utf8()..];
+                } else {
+                    return Err(ParseError::new(format!(
+                        "expected `{}` in `{}`",
+                        fc, input
+                    )));
+                }
+                continue;
+            }
+            match fmt_chars.next() {
+                Some('H') => hour = take_digits(&mut remaining)?.parse().map_err(|e| ParseError::new(e.to_string()))?,
+                Some('M') => minute = take_digits(&mut remaining)?.parse().map_err(|e| ParseError::new(e.to_string()))?,
+                Some('S') => second = take_digits(&mut remaining)?.parse().map_err(|e| ParseError::new(e.to_string()))?,
+                Some('@') => {
+                    centisecond = take_digits(&mut remaining)?.parse().map_err(|e| ParseError::new(e.to_string()))?
+                }
+                Some(other) => {
+                    return Err(ParseError::new(format!("unsupported format specifier %{other}")))
+                }
+                None => return Err(ParseError::new("dangling `%` in format string")),
+            }
+        }
+
+        CSTime::from_hms_cs(hour, minute, second, centisecond).map_err(|e| ParseError::new(e.to_string()))
+    }
 }
 
+/// Consumes a run of ASCII digits from the front of `input`, advancing it
+/// past them and returning the consumed slice.
+#[cfg(feature = "std")]
+fn take_digits<'a>(input: &mut &'a str) -> Result<&'a str, ParseError> {
+    let digit_count = input.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digit_count == 0 {
+        return Err(ParseError::new(format!("expected digits in `{input}`")));
+    }
+    let (digits, rest) = input.split_at(digit_count);
+    *input = rest;
+    Ok(digits)
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for CSTime {
+    /// Formats a `CSTime` as `HH:MM:SS.cc`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format("%H:%M:%S.%@"))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::str::FromStr for CSTime {
+    type Err = ParseError;
+
+    /// Parses a `CSTime` from an `HH:MM:SS.cc` clock string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CSTime::parse(s, "%H:%M:%S.%@")
+    }
+}
+
+#[cfg(feature = "time")]
 impl From<time::Time> for CSTime {
     /// Convert a `time::Time` value into a CSTime time value.
     ///
     /// # Examples
     /// Using `from()`
     /// ```
-    /// use cstime::CSTime;
+    /// use clarion::CSTime;
     /// use time::macros::time;
     /// let time = CSTime::from(time!(16:34:00));
     /// assert_eq!(time.time, 5964000);
     /// ```
     /// Using `into()`
     /// ```
-    /// let cs_time = cstime::CSTime::new(5964000);
+    /// let cs_time = clarion::CSTime::new(5964000);
     /// let time: time::Time = cs_time.into();
     /// assert_eq!(time, time::macros::time!(16:34:00))
     /// ```
@@ -47,12 +295,13 @@ impl From<time::Time> for CSTime {
     }
 }
 
+#[cfg(feature = "time")]
 impl From<CSTime> for time::Time {
     /// Convert a `CSTime` time value into a `time::Time` value.
     ///
     /// # Examples
     /// ```
-    /// use cstime::CSTime;
+    /// use clarion::CSTime;
     /// use time::macros::time;
     /// let time = time::Time::from(CSTime::new(5964000));
     /// assert_eq!(time, time!(16:34:00));
@@ -63,12 +312,143 @@ impl From<CSTime> for time::Time {
     }
 }
 
+#[cfg(feature = "time")]
+impl Add<Duration> for CSTime {
+    type Output = CSTime;
+
+    /// Add a `time::Duration` to a `CSTime`, wrapping around midnight
+    /// (using Euclidean remainder) if the centisecond count leaves the
+    /// valid day range.
+    ///
+    /// # Examples
+    /// ```
+    /// let cs_time = clarion::CSTime::new(0) + time::Duration::seconds(-1);
+    /// assert_eq!(cs_time.time, 8_639_900);
+    /// ```
+    fn add(self, rhs: Duration) -> Self::Output {
+        let centiseconds = self.time as i128 + rhs.whole_milliseconds() / 10;
+        CSTime::new(centiseconds.rem_euclid(8_640_000) as i32)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Sub<Duration> for CSTime {
+    type Output = CSTime;
+
+    /// Subtract a `time::Duration` from a `CSTime`, wrapping around
+    /// midnight (using Euclidean remainder) if the centisecond count
+    /// leaves the valid day range.
+    ///
+    /// # Examples
+    /// ```
+    /// let cs_time = clarion::CSTime::new(0) - time::Duration::seconds(1);
+    /// assert_eq!(cs_time.time, 8_639_900);
+    /// ```
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Sub<CSTime> for CSTime {
+    type Output = Duration;
+
+    /// Get the signed `time::Duration` gap between two `CSTime` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use clarion::CSTime;
+    /// let gap = CSTime::new(100) - CSTime::new(1);
+    /// assert_eq!(gap, time::Duration::milliseconds(990));
+    /// ```
+    fn sub(self, rhs: CSTime) -> Self::Output {
+        Duration::milliseconds((self.time - rhs.time) as i64 * 10)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl serde::Serialize for CSTime {
+    /// Serializes a `CSTime` as an `HH:MM:SS.cc` clock string for
+    /// human-readable formats, or as the raw centisecond `i32` for compact
+    /// binary formats.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.format("%H:%M:%S.%@"))
+        } else {
+            serializer.serialize_i32(self.time)
+        }
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de> serde::Deserialize<'de> for CSTime {
+    /// Deserializes a `CSTime` from either an `HH:MM:SS.cc` clock string or
+    /// a raw centisecond `i32`, rejecting values outside a single day.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CSTimeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CSTimeVisitor {
+            type Value = CSTime;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an `HH:MM:SS.cc` clock string or a raw centisecond i32")
+            }
+
+            fn visit_i32<E>(self, value: i32) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if (0..8_640_000).contains(&value) {
+                    Ok(CSTime::new(value))
+                } else {
+                    Err(E::custom("CSTime value out of range for a single day"))
+                }
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_i32(i32::try_from(value).map_err(E::custom)?)
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_i32(i32::try_from(value).map_err(E::custom)?)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                CSTime::parse(value, "%H:%M:%S.%@").map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CSTimeVisitor)
+        } else {
+            deserializer.deserialize_i32(CSTimeVisitor)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use time::{macros::time, Time};
-
     use crate::cstime::CSTime;
 
+    #[cfg(feature = "time")]
+    use time::{macros::time, Duration, Time};
+
+    #[cfg(feature = "time")]
     #[test]
     fn cstime_to_time_i32_max() {
         let time = CSTime::new(i32::MAX);
@@ -76,6 +456,7 @@ mod tests {
         assert_eq!(result, time!(13:13:56.47));
     }
 
+    #[cfg(feature = "time")]
     #[test]
     fn cstime_to_time_i32_min() {
         let time = CSTime::new(i32::MIN);
@@ -83,6 +464,7 @@ mod tests {
         assert_eq!(result, time!(10:46:03.52));
     }
 
+    #[cfg(feature = "time")]
     #[test]
     fn cstime_to_time_zero() {
         let time = CSTime::new(0);
@@ -90,6 +472,7 @@ mod tests {
         assert_eq!(result, time!(00:00:00));
     }
 
+    #[cfg(feature = "time")]
     #[test]
     fn cstime_to_time_one() {
         let time = CSTime::new(1);
@@ -97,6 +480,7 @@ mod tests {
         assert_eq!(result, time!(00:00:00.01));
     }
 
+    #[cfg(feature = "time")]
     #[test]
     fn cstime_to_time_negative_one() {
         let time = CSTime::new(-1);
@@ -104,6 +488,7 @@ mod tests {
         assert_eq!(result, time!(23:59:59.99));
     }
 
+    #[cfg(feature = "time")]
     #[test]
     fn cstime_to_time_reversibility() {
         let time = time!(17:30:43);
@@ -111,4 +496,155 @@ mod tests {
         let time2 = result.into();
         assert_eq!(time, time2);
     }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn serde_human_readable_round_trip() {
+        let cs_time = CSTime::new(5964000);
+        let json = serde_json::to_string(&cs_time).unwrap();
+        assert_eq!(json, "\"16:34:00.00\"");
+        let result: CSTime = serde_json::from_str(&json).unwrap();
+        assert_eq!(result, cs_time);
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn serde_binary_round_trip() {
+        let cs_time = CSTime::new(5964000);
+        let encoded = bincode::serialize(&cs_time).unwrap();
+        let result: CSTime = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(result, cs_time);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn add_duration_wraps_negative() {
+        let cs_time = CSTime::new(0) + Duration::seconds(-1);
+        assert_eq!(cs_time.time, 8_639_900);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn sub_duration_wraps() {
+        let cs_time = CSTime::new(0) - Duration::seconds(1);
+        assert_eq!(cs_time.time, 8_639_900);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn sub_cstime_gap() {
+        let gap = CSTime::new(100) - CSTime::new(1);
+        assert_eq!(gap, Duration::milliseconds(990));
+    }
+
+    #[test]
+    fn try_new_in_range() {
+        assert!(CSTime::try_new(5964000).is_ok());
+    }
+
+    #[test]
+    fn try_new_out_of_range() {
+        assert!(CSTime::try_new(i32::MAX).is_err());
+        assert!(CSTime::try_new(-1).is_err());
+    }
+
+    #[test]
+    fn normalize_negative() {
+        assert_eq!(CSTime::new(-1).normalize().time, 8_639_999);
+    }
+
+    #[test]
+    fn normalize_in_range_is_identity() {
+        assert_eq!(CSTime::new(100).normalize().time, 100);
+    }
+
+    #[test]
+    fn from_hms_cs_valid() {
+        let cs_time = CSTime::from_hms_cs(16, 34, 0, 0).unwrap();
+        assert_eq!(cs_time.time, 5964000);
+    }
+
+    #[test]
+    fn from_hms_cs_invalid_hour() {
+        assert!(CSTime::from_hms_cs(24, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn from_hms_cs_invalid_centisecond() {
+        assert!(CSTime::from_hms_cs(0, 0, 0, 100).is_err());
+    }
+
+    #[test]
+    fn component_accessors() {
+        let cs_time = CSTime::new(5964050);
+        assert_eq!(cs_time.hour(), 16);
+        assert_eq!(cs_time.minute(), 34);
+        assert_eq!(cs_time.second(), 0);
+        assert_eq!(cs_time.centisecond(), 50);
+    }
+
+    #[test]
+    fn component_accessors_normalize_negative() {
+        let cs_time = CSTime::new(-1);
+        assert_eq!(cs_time.hour(), 23);
+        assert_eq!(cs_time.minute(), 59);
+        assert_eq!(cs_time.second(), 59);
+        assert_eq!(cs_time.centisecond(), 99);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn format_clock_string() {
+        let cs_time = CSTime::new(5964000);
+        assert_eq!(cs_time.format("%H:%M:%S.%@"), "16:34:00.00");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn format_unsupported_specifier_passes_through() {
+        let cs_time = CSTime::new(0);
+        assert_eq!(cs_time.format("%Y-%H"), "%Y-00");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_clock_string() {
+        let cs_time = CSTime::parse("16:34:00.00", "%H:%M:%S.%@").unwrap();
+        assert_eq!(cs_time.time, 5964000);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_missing_fields_default_to_zero() {
+        let cs_time = CSTime::parse("16:34", "%H:%M").unwrap();
+        assert_eq!(cs_time.time, 5964000);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_rejects_overflowing_component() {
+        assert!(CSTime::parse("24:00:00.00", "%H:%M:%S.%@").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_rejects_mismatched_literal() {
+        assert!(CSTime::parse("16-34-00.00", "%H:%M:%S.%@").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_round_trip() {
+        use std::str::FromStr;
+        let cs_time = CSTime::new(5964000);
+        assert_eq!(cs_time.to_string(), "16:34:00.00");
+        assert_eq!(CSTime::from_str(&cs_time.to_string()).unwrap(), cs_time);
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn serde_rejects_out_of_day_integer() {
+        let result: Result<CSTime, _> = serde_json::from_value(serde_json::json!(8_640_000));
+        assert!(result.is_err());
+    }
 }
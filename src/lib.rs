@@ -11,14 +11,38 @@
 //! a 24-bit RGB color in 0xBBGGRR format, as opposed to the standard
 //! 0xRRGGBB format, where RR represents red, GG represents green, and BB
 //! represents the blue 8-bit components of the 24 bit color respectively.
+//!
+//! Enabling the `serde` feature derives/implements `Serialize` and
+//! `Deserialize` for `ClarionColor`, `ClarionDate`, `ClarionTime`, and
+//! `RgbColor`.
+//!
+//! `CSDate`, `CSTime`, and `CSDateTime` are a parallel date/time family
+//! that mirrors the Clarion types. The `time`-crate conversions and
+//! `Duration` arithmetic of `CSTime` and `CSDateTime` are behind the `time`
+//! feature; `CSDate` and its `time::Date` conversions are always compiled
+//! and use the `time` crate unconditionally. They live in private
+//! `csdate`/`cstime`/`csdatetime`/`cserr` modules and are re-exported flat
+//! at the crate root alongside the Clarion types, e.g. `clarion::CSTime`.
 mod clarion_color;
 mod clarion_date;
+mod clarion_date_time;
 mod clarion_err;
 mod clarion_time;
+mod cserr;
+mod csdate;
+mod csdatetime;
+mod cstime;
 mod rgb_color;
 
 pub use crate::clarion_color::ClarionColor;
 pub use crate::clarion_date::ClarionDate;
+pub use crate::clarion_date_time::ClarionDateTime;
 pub use crate::clarion_err::ClarionErr;
 pub use crate::clarion_time::ClarionTime;
+pub use crate::cserr::{CSErr, RangeError};
+#[cfg(feature = "std")]
+pub use crate::cserr::ParseError;
+pub use crate::csdate::CSDate;
+pub use crate::csdatetime::CSDateTime;
+pub use crate::cstime::CSTime;
 pub use crate::rgb_color::RgbColor;
@@ -2,6 +2,7 @@
 use crate::ClarionColor;
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Defines a 24-bit color within the RGB color space, represented by
 /// three 8-bit integers, one integer for each segment.
 ///